@@ -1,3 +1,65 @@
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// Numeric types that [`Tukey`] can filter.
+///
+/// Quartile and deviation arithmetic is always carried out in `f64`; this trait only
+/// needs to get a lossless-enough `f64` view of a value so the original type can be
+/// returned to the caller unchanged.
+pub trait ToF64: PartialOrd + Copy {
+    /// Converts the value to `f64` for use in quartile and deviation arithmetic.
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_to_f64 {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl ToF64 for $t {
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_to_f64!(f32, f64, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// Selects how Q1 and Q3 are estimated from sorted data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuartileMethod {
+    /// Tukey's original "median of halves" hinge, used by [`Tukey::tukeyize`].
+    Hinge,
+    /// R-5 (Hyndman–Fan type 5) interpolation between order statistics.
+    Interpolated,
+}
+
+/// How NaN values are handled by [`Tukey::tukeyize_checked`] and [`Tukey::try_tukeyize`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NanPolicy {
+    /// Panic on the first NaN, matching [`Tukey::tukeyize`]'s existing behavior.
+    Propagate,
+    /// Silently remove NaNs from the data before filtering.
+    Drop,
+    /// Return a [`TukeyError`] describing the offending value instead of panicking.
+    Error,
+}
+
+/// An error produced by a [`Tukey`] method run under [`NanPolicy::Error`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TukeyError {
+    /// The index of the first NaN value found in the input.
+    pub index: usize,
+}
+
+impl std::fmt::Display for TukeyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "value at index {} is NaN", self.index)
+    }
+}
+
+impl std::error::Error for TukeyError {}
+
 /// Values that can be filtered using Tukey's range test.
 ///
 /// # Usage
@@ -8,51 +70,389 @@
 /// let filtered = values.tukeyize();
 /// assert_eq!(filtered, vec![1.0, 6.0, 3.0, 3.0, 2.0, 8.0]);
 /// ```
-pub trait Tukey {
+pub trait Tukey<T> {
     /// Removes extreme values using Tukey's method.
     ///
     /// Values outside the inclusive range `[Q1 - 1.5 * IQR, Q3 + 1.5 * IQR]` are removed.
-    fn tukeyize(&self) -> Vec<f64>;
+    fn tukeyize(&self) -> Vec<T>;
+
+    /// Removes extreme values using Tukey's method, estimating Q1 and Q3 with the given
+    /// [`QuartileMethod`] instead of the default hinge.
+    fn tukeyize_with(&self, method: QuartileMethod) -> Vec<T>;
+
+    /// Removes extreme values using the Hampel identifier.
+    ///
+    /// The median `M` and the median absolute deviation (MAD) of the data are used to
+    /// compute a modified z-score `(x - M) / (1.4826 * MAD)` for each value; any value
+    /// whose modified z-score exceeds `3.5` in magnitude is removed. This is more robust
+    /// than the IQR fence on skewed or heavy-tailed data. When the MAD is zero, all values
+    /// are kept.
+    fn hampelize(&self) -> Vec<T>;
+
+    /// Removes extreme values using the Hampel identifier, with a configurable modified
+    /// z-score `threshold` instead of the conventional `3.5`.
+    fn hampelize_with(&self, threshold: f64) -> Vec<T>;
+
+    /// Parallel variant of [`Tukey::tukeyize`] that sorts and filters using rayon.
+    ///
+    /// Requires the `rayon` feature. Produces bitwise-identical output to `tukeyize`.
+    #[cfg(feature = "rayon")]
+    fn par_tukeyize(&self) -> Vec<T>
+    where
+        T: Send + Sync;
+
+    /// Removes extreme values using Tukey's method, honoring `policy` for NaN values
+    /// instead of always panicking.
+    fn tukeyize_checked(&self, policy: NanPolicy) -> Result<Vec<T>, TukeyError>;
+
+    /// Removes extreme values using Tukey's method, returning a [`TukeyError`] instead
+    /// of panicking when the data contains a NaN.
+    fn try_tukeyize(&self) -> Result<Vec<T>, TukeyError> {
+        self.tukeyize_checked(NanPolicy::Error)
+    }
 }
 
-impl Tukey for [f64] {
+impl<T: ToF64> Tukey<T> for [T] {
     /// Removes extreme values using Tukey's method.
-    fn tukeyize(&self) -> Vec<f64> {
-        trim(self)
+    fn tukeyize(&self) -> Vec<T> {
+        trim(self, QuartileMethod::Hinge)
+    }
+
+    /// Removes extreme values using Tukey's method, estimating Q1 and Q3 with the given
+    /// [`QuartileMethod`] instead of the default hinge.
+    fn tukeyize_with(&self, method: QuartileMethod) -> Vec<T> {
+        trim(self, method)
+    }
+
+    /// Removes extreme values using the Hampel identifier.
+    fn hampelize(&self) -> Vec<T> {
+        hampel(self, HAMPEL_THRESHOLD)
+    }
+
+    /// Removes extreme values using the Hampel identifier, with a configurable modified
+    /// z-score `threshold` instead of the conventional `3.5`.
+    fn hampelize_with(&self, threshold: f64) -> Vec<T> {
+        hampel(self, threshold)
+    }
+
+    /// Parallel variant of [`Tukey::tukeyize`] that sorts and filters using rayon.
+    #[cfg(feature = "rayon")]
+    fn par_tukeyize(&self) -> Vec<T>
+    where
+        T: Send + Sync,
+    {
+        par_trim(self, QuartileMethod::Hinge)
+    }
+
+    /// Removes extreme values using Tukey's method, honoring `policy` for NaN values
+    /// instead of always panicking.
+    fn tukeyize_checked(&self, policy: NanPolicy) -> Result<Vec<T>, TukeyError> {
+        checked_trim(self, QuartileMethod::Hinge, policy)
     }
 }
 
-impl Tukey for Vec<f64> {
+impl<T: ToF64> Tukey<T> for Vec<T> {
     /// Removes extreme values using Tukey's method.
-    fn tukeyize(&self) -> Vec<f64> {
+    fn tukeyize(&self) -> Vec<T> {
         self.as_slice().tukeyize()
     }
+
+    /// Removes extreme values using Tukey's method, estimating Q1 and Q3 with the given
+    /// [`QuartileMethod`] instead of the default hinge.
+    fn tukeyize_with(&self, method: QuartileMethod) -> Vec<T> {
+        self.as_slice().tukeyize_with(method)
+    }
+
+    /// Removes extreme values using the Hampel identifier.
+    fn hampelize(&self) -> Vec<T> {
+        self.as_slice().hampelize()
+    }
+
+    /// Removes extreme values using the Hampel identifier, with a configurable modified
+    /// z-score `threshold` instead of the conventional `3.5`.
+    fn hampelize_with(&self, threshold: f64) -> Vec<T> {
+        self.as_slice().hampelize_with(threshold)
+    }
+
+    /// Parallel variant of [`Tukey::tukeyize`] that sorts and filters using rayon.
+    #[cfg(feature = "rayon")]
+    fn par_tukeyize(&self) -> Vec<T>
+    where
+        T: Send + Sync,
+    {
+        self.as_slice().par_tukeyize()
+    }
+
+    /// Removes extreme values using Tukey's method, honoring `policy` for NaN values
+    /// instead of always panicking.
+    fn tukeyize_checked(&self, policy: NanPolicy) -> Result<Vec<T>, TukeyError> {
+        self.as_slice().tukeyize_checked(policy)
+    }
+}
+
+/// Converts `values` to `f64` and sorts them ascending, panicking if any comparison is
+/// undefined (e.g. a `NaN`).
+fn sorted_f64<T: ToF64>(values: &[T]) -> Vec<f64> {
+    let mut order = values.iter().map(|x| x.to_f64()).collect::<Vec<_>>();
+    order.sort_by(|a, b| {
+        a.partial_cmp(b).unwrap_or_else(|| {
+            panic!("Cannot compare values {a} and {b} because at least one is NaN")
+        })
+    });
+    order
 }
 
 /// Removes extreme values using Tukey's method.
 ///
-/// The quartiles are computed as medians of the lower and upper halves of the sorted data.
-fn trim(values: &[f64]) -> Vec<f64> {
+/// Q1 and Q3 are estimated from the sorted data using `method`.
+fn trim<T: ToF64>(values: &[T], method: QuartileMethod) -> Vec<T> {
+    TukeyFilter::new().method(method).run(values).retained
+}
+
+/// A total order over `f64` that sorts NaNs deterministically instead of making
+/// comparison undefined, in the spirit of bencher's `local_cmp`: positive NaNs sort after
+/// every other value and negative NaNs before.
+fn total_cmp(a: f64, b: f64) -> std::cmp::Ordering {
+    a.total_cmp(&b)
+}
+
+/// [`trim`], but honoring a [`NanPolicy`] for NaN values instead of unconditionally
+/// panicking on them.
+///
+/// As with `trim`, inputs of fewer than 3 elements are returned untouched without being
+/// scanned for NaNs, so `try_tukeyize` never reports an error that `tukeyize` wouldn't
+/// have either silently ignored.
+fn checked_trim<T: ToF64>(
+    values: &[T],
+    method: QuartileMethod,
+    policy: NanPolicy,
+) -> Result<Vec<T>, TukeyError> {
+    if values.len() < 3 {
+        return Ok(values.to_vec());
+    }
+    let values = match policy {
+        NanPolicy::Propagate => return Ok(trim(values, method)),
+        NanPolicy::Drop => values
+            .iter()
+            .copied()
+            .filter(|x| !x.to_f64().is_nan())
+            .collect::<Vec<_>>(),
+        NanPolicy::Error => {
+            if let Some(index) = values.iter().position(|x| x.to_f64().is_nan()) {
+                return Err(TukeyError { index });
+            }
+            values.to_vec()
+        }
+    };
+    if values.len() < 3 {
+        return Ok(values);
+    }
+    let mut order = values.iter().map(|x| x.to_f64()).collect::<Vec<_>>();
+    order.sort_by(|a, b| total_cmp(*a, *b));
+    let (_, _, _, lower_fence, upper_fence) = fences(method, 1.5, &order);
+    Ok(values
+        .iter()
+        .copied()
+        .filter(|x| {
+            let x = x.to_f64();
+            x >= lower_fence && x <= upper_fence
+        })
+        .collect())
+}
+
+/// Parallel equivalent of [`trim`] using rayon for the sort and filter passes.
+///
+/// The sort is stable and NaNs are rejected the same way as the sequential path, so this
+/// produces bitwise-identical output to `trim` for the same input and `k = 1.5`.
+#[cfg(feature = "rayon")]
+fn par_trim<T>(values: &[T], method: QuartileMethod) -> Vec<T>
+where
+    T: ToF64 + Send + Sync,
+{
     if values.len() < 3 {
         return values.to_vec();
     }
-    let mut order = values.to_vec();
-    order.sort_by(|a, b| {
+    let mut order = values.par_iter().map(|x| x.to_f64()).collect::<Vec<_>>();
+    order.par_sort_by(|a, b| {
         a.partial_cmp(b).unwrap_or_else(|| {
             panic!("Cannot compare values {a} and {b} because at least one is NaN")
         })
     });
-    let (q1, q3) = hinge(&order);
-    let range = q3 - q1;
-    let min = q1 - (1.5 * range);
-    let max = q3 + (1.5 * range);
+    let (_, _, _, lower_fence, upper_fence) = fences(method, 1.5, &order);
+    values
+        .par_iter()
+        .copied()
+        .filter(|x| {
+            let x = x.to_f64();
+            x >= lower_fence && x <= upper_fence
+        })
+        .collect()
+}
+
+/// The full report produced by running a [`TukeyFilter`] over a slice of values.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TukeyReport<T> {
+    /// The estimated first quartile.
+    pub q1: f64,
+    /// The estimated third quartile.
+    pub q3: f64,
+    /// The interquartile range, `q3 - q1`.
+    pub iqr: f64,
+    /// The lower fence, `q1 - k * iqr`. Values below this are outliers.
+    pub lower_fence: f64,
+    /// The upper fence, `q3 + k * iqr`. Values above this are outliers.
+    pub upper_fence: f64,
+    /// The values that fell within `[lower_fence, upper_fence]`.
+    pub retained: Vec<T>,
+    /// The values that fell outside `[lower_fence, upper_fence]`.
+    pub removed: Vec<T>,
+}
+
+/// A configurable Tukey's-fence outlier filter.
+///
+/// `tukeyize` is a thin wrapper over `TukeyFilter::new().run(values).retained` with the
+/// conventional fence multiplier `k = 1.5`. Use this builder directly to change `k` (e.g.
+/// `3.0` for Tukey's "far out" values), choose a [`QuartileMethod`], or inspect the
+/// computed bounds and removed values.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TukeyFilter {
+    k: f64,
+    method: QuartileMethod,
+}
+
+impl Default for TukeyFilter {
+    fn default() -> Self {
+        Self {
+            k: 1.5,
+            method: QuartileMethod::Hinge,
+        }
+    }
+}
+
+impl TukeyFilter {
+    /// Creates a filter using Tukey's conventional fence multiplier `k = 1.5` and the
+    /// default hinge quartile estimate.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the fence multiplier `k`. Tukey's "outer"/"far out" fence uses `k = 3.0`.
+    pub fn k(mut self, k: f64) -> Self {
+        self.k = k;
+        self
+    }
+
+    /// Sets how Q1 and Q3 are estimated.
+    pub fn method(mut self, method: QuartileMethod) -> Self {
+        self.method = method;
+        self
+    }
+
+    /// Runs the filter over `values`, returning the computed bounds along with the
+    /// retained and removed values.
+    ///
+    /// With fewer than 3 values, every value is retained, `q1`, `q3` and `iqr` are
+    /// reported as `0.0`, and the fences as negative and positive infinity.
+    pub fn run<T: ToF64>(&self, values: &[T]) -> TukeyReport<T> {
+        if values.len() < 3 {
+            return TukeyReport {
+                q1: 0.0,
+                q3: 0.0,
+                iqr: 0.0,
+                lower_fence: f64::NEG_INFINITY,
+                upper_fence: f64::INFINITY,
+                retained: values.to_vec(),
+                removed: Vec::new(),
+            };
+        }
+        let order = sorted_f64(values);
+        let (q1, q3, iqr, lower_fence, upper_fence) = fences(self.method, self.k, &order);
+        let (retained, removed) = values.iter().copied().partition(|x| {
+            let x = x.to_f64();
+            x >= lower_fence && x <= upper_fence
+        });
+        TukeyReport {
+            q1,
+            q3,
+            iqr,
+            lower_fence,
+            upper_fence,
+            retained,
+            removed,
+        }
+    }
+}
+
+/// The conventional modified z-score magnitude above which [`Tukey::hampelize`]
+/// considers a value an outlier. Use [`Tukey::hampelize_with`] to override it.
+const HAMPEL_THRESHOLD: f64 = 3.5;
+
+/// The consistency factor that scales the median absolute deviation to match the
+/// standard deviation of a normal distribution.
+const MAD_SCALE: f64 = 1.4826;
+
+/// Removes extreme values using the Hampel identifier.
+///
+/// Falls back to keeping all values when the MAD is zero (more than half the values are
+/// identical) to avoid dividing by zero.
+fn hampel<T: ToF64>(values: &[T], threshold: f64) -> Vec<T> {
+    if values.len() < 3 {
+        return values.to_vec();
+    }
+    let order = sorted_f64(values);
+    let median = middle(&order);
+    let mut deviations = order.iter().map(|x| (x - median).abs()).collect::<Vec<_>>();
+    deviations.sort_by(|a, b| {
+        a.partial_cmp(b).unwrap_or_else(|| {
+            panic!("Cannot compare values {a} and {b} because at least one is NaN")
+        })
+    });
+    let mad = middle(&deviations);
+    if mad == 0.0 {
+        return values.to_vec();
+    }
+    let scale = MAD_SCALE * mad;
     values
         .iter()
         .copied()
-        .filter(|x| *x >= min && *x <= max)
+        .filter(|x| ((x.to_f64() - median) / scale).abs() <= threshold)
         .collect()
 }
 
+/// Estimates Q1 and Q3 from already-sorted values using `method`.
+fn quartiles(method: QuartileMethod, values: &[f64]) -> (f64, f64) {
+    match method {
+        QuartileMethod::Hinge => hinge(values),
+        QuartileMethod::Interpolated => (quantile(values, 0.25), quantile(values, 0.75)),
+    }
+}
+
+/// Computes Q1, Q3, the IQR, and the lower/upper fences for fence multiplier `k` from
+/// already-sorted values. The single source of truth for the fence formula, shared by
+/// [`TukeyFilter::run`], `checked_trim`, and `par_trim` so it is never duplicated.
+fn fences(method: QuartileMethod, k: f64, values: &[f64]) -> (f64, f64, f64, f64, f64) {
+    let (q1, q3) = quartiles(method, values);
+    let iqr = q3 - q1;
+    let lower_fence = q1 - (k * iqr);
+    let upper_fence = q3 + (k * iqr);
+    (q1, q3, iqr, lower_fence, upper_fence)
+}
+
+/// Estimates the `q`-quantile of already-sorted values using R-5 (Hyndman–Fan type 5)
+/// interpolation between order statistics.
+fn quantile(values: &[f64], q: f64) -> f64 {
+    let h = values.len() as f64 * q - 0.5;
+    let f = h.floor();
+    if f < 0.0 {
+        return values[0];
+    }
+    let f = f as usize;
+    if f >= values.len() - 1 {
+        return values[values.len() - 1];
+    }
+    values[f] + (h - f as f64) * (values[f + 1] - values[f])
+}
+
 /// Calculates Tukey-style quartiles from already-sorted values.
 fn hinge(values: &[f64]) -> (f64, f64) {
     let mid = values.len() / 2;
@@ -136,6 +536,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn array_removes_extreme_values_using_hampel_identifier() {
+        let values = make("hampel");
+        let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+        let result = values.hampelize();
+        assert!(
+            !result.contains(&max) && !result.contains(&min),
+            "The outliers were not removed"
+        );
+    }
+
+    #[test]
+    fn array_keeps_all_values_when_median_absolute_deviation_is_zero() {
+        let mut state = "hampel-mad"
+            .bytes()
+            .fold(0u64, |s, b| s.wrapping_add(u64::from(b)).wrapping_mul(31));
+        let item = (roll(&mut state) * 1000.0) - 500.0;
+        let values = vec![item, item, item, item, item];
+        let result = values.hampelize();
+        assert_eq!(
+            result, values,
+            "The values were removed even though there were no outliers"
+        );
+    }
+
+    #[test]
+    fn hampelize_with_a_looser_threshold_keeps_more_values() {
+        let values = make("hampel-threshold");
+        let strict = values.hampelize_with(1.0);
+        let loose = values.hampelize_with(10.0);
+        assert!(loose.len() >= strict.len());
+        assert_eq!(values.hampelize(), values.hampelize_with(3.5));
+    }
+
     #[test]
     fn array_produces_the_same_result_when_called_concurrently() {
         let values = make("somerandomseed");
@@ -151,4 +586,123 @@ mod tests {
             "The concurrent calls produced different results"
         );
     }
+
+    #[test]
+    fn array_tukeyizes_using_interpolated_quartiles() {
+        let values = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0];
+        let result = values.tukeyize_with(QuartileMethod::Interpolated);
+        assert_eq!(result, values.to_vec());
+    }
+
+    #[test]
+    fn interpolated_quartiles_differ_from_the_hinge_on_an_odd_sized_sample() {
+        let values: Vec<f64> = (1..=9).map(f64::from).collect();
+        let hinge = TukeyFilter::new().method(QuartileMethod::Hinge).run(&values);
+        let interpolated = TukeyFilter::new()
+            .method(QuartileMethod::Interpolated)
+            .run(&values);
+        assert_eq!((hinge.q1, hinge.q3), (2.5, 7.5));
+        assert_eq!((interpolated.q1, interpolated.q3), (2.75, 7.25));
+        assert_ne!(hinge.q1, interpolated.q1, "Interpolated Q1 should differ from the hinge");
+        assert_ne!(hinge.q3, interpolated.q3, "Interpolated Q3 should differ from the hinge");
+    }
+
+    #[test]
+    fn filter_reports_bounds_and_removed_values() {
+        let values = make("filter");
+        let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+        let report = TukeyFilter::new().run(&values);
+        assert_eq!(report.iqr, report.q3 - report.q1);
+        assert_eq!(report.retained, values.tukeyize());
+        assert!(report.removed.contains(&max) && report.removed.contains(&min));
+        for x in &report.retained {
+            assert!(*x >= report.lower_fence && *x <= report.upper_fence);
+        }
+        for x in &report.removed {
+            assert!(*x < report.lower_fence || *x > report.upper_fence);
+        }
+    }
+
+    #[test]
+    fn filter_with_larger_k_keeps_more_values() {
+        let values = make("wide-fence");
+        let narrow = TukeyFilter::new().k(1.5).run(&values);
+        let wide = TukeyFilter::new().k(3.0).run(&values);
+        assert!(wide.retained.len() >= narrow.retained.len());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn array_par_tukeyize_matches_sequential_result() {
+        let values = make("rayon-parity");
+        let sequential = values.tukeyize();
+        let parallel = values.par_tukeyize();
+        assert_eq!(
+            parallel, sequential,
+            "The parallel path produced different results than the sequential one"
+        );
+    }
+
+    #[test]
+    fn checked_propagate_panics_on_nan_like_tukeyize() {
+        let values = vec![1.0, 2.0, 3.0, 4.0, f64::NAN];
+        let result = std::panic::catch_unwind(|| values.tukeyize_checked(NanPolicy::Propagate));
+        assert!(result.is_err(), "Propagate did not panic on a NaN");
+    }
+
+    #[test]
+    fn checked_drop_removes_nans_before_filtering() {
+        let mut values = make("nan-drop");
+        values.push(f64::NAN);
+        let result = values
+            .tukeyize_checked(NanPolicy::Drop)
+            .expect("Drop should never return an error");
+        assert!(!result.iter().any(|x| x.is_nan()), "A NaN survived Drop");
+    }
+
+    #[test]
+    fn checked_error_reports_the_index_of_the_first_nan() {
+        let values = vec![1.0, 2.0, f64::NAN, 4.0, 5.0, 6.0];
+        let error = values
+            .tukeyize_checked(NanPolicy::Error)
+            .expect_err("Error should report the NaN instead of panicking");
+        assert_eq!(error.index, 2);
+    }
+
+    fn same_bits(a: &[f64], b: &[f64]) -> bool {
+        a.len() == b.len() && a.iter().zip(b).all(|(x, y)| x.to_bits() == y.to_bits())
+    }
+
+    #[test]
+    fn checked_matches_tukeyize_on_too_few_elements_even_with_a_nan() {
+        let values = vec![f64::NAN, 1.0];
+        assert!(same_bits(&values.tukeyize(), &values));
+        let error_result = values
+            .tukeyize_checked(NanPolicy::Error)
+            .expect("Error should not scan inputs that tukeyize() handles without looking at them");
+        assert!(same_bits(&error_result, &values));
+        let drop_result = values
+            .tukeyize_checked(NanPolicy::Drop)
+            .expect("Drop should not strip NaNs from inputs that tukeyize() passes through untouched");
+        assert!(same_bits(&drop_result, &values));
+        let try_result = values.try_tukeyize().expect("try_tukeyize should match tukeyize here");
+        assert!(same_bits(&try_result, &values));
+    }
+
+    #[test]
+    fn try_tukeyize_is_equivalent_to_error_policy() {
+        let values = vec![1.0, 2.0, f64::NAN, 4.0, 5.0, 6.0];
+        assert_eq!(
+            values.try_tukeyize(),
+            values.tukeyize_checked(NanPolicy::Error)
+        );
+    }
+
+    #[test]
+    fn array_tukeyizes_integers_without_precision_loss() {
+        let values: Vec<i32> = vec![1, 6, 3, 8888, 3, 2, 8, -19292];
+        let result = values.tukeyize();
+        assert_eq!(result, vec![1, 6, 3, 3, 2, 8]);
+    }
 }